@@ -54,10 +54,15 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
+use core::borrow::{Borrow, BorrowMut};
 use core::cell::UnsafeCell;
 use core::ops::{Deref, DerefMut};
 
-use self::Inner::{Evaluating, Unevaluated, Value};
+use self::Inner::{Evaluating, Poisoned, Unevaluated, Value};
+
+pub mod list;
+
+pub use self::list::LazyList;
 
 /// A lazily evaluated value.
 pub struct Thunk<E, V>(UnsafeCell<Inner<E, V>>);
@@ -111,15 +116,27 @@ where
     }
 
     /// Force evaluation of a thunk.
+    ///
+    /// If the closure used to evaluate the thunk panics, the thunk is
+    /// poisoned: every subsequent call to `force` (or dereference) panics
+    /// with a message indicating that the thunk was poisoned by an earlier
+    /// panic, rather than the misleading message for an in-progress
+    /// evaluation.
     pub fn force(&self) {
         match unsafe { &*self.0.get() } {
             Value(_) => return,
             Evaluating => panic!("Thunk::force called during evaluation."),
+            Poisoned => panic!("thunk poisoned by previous panic during evaluation"),
             Unevaluated(_) => (),
         }
         unsafe {
             match core::ptr::replace(self.0.get(), Evaluating) {
-                Unevaluated(e) => *self.0.get() = Value(e.evaluate()),
+                Unevaluated(e) => {
+                    let guard = PoisonGuard(&self.0);
+                    let v = e.evaluate();
+                    core::mem::forget(guard);
+                    *self.0.get() = Value(v);
+                }
                 _ => unreachable!(),
             };
         }
@@ -139,6 +156,91 @@ where
             _ => unreachable!(),
         }
     }
+
+    /// Build a new, unforced thunk that applies `f` to the value of `self`.
+    ///
+    /// `self` is not forced until the returned thunk is forced.
+    ///
+    /// ~~~
+    /// # use lazy_st::lazy;
+    /// let val = lazy!(7).map(|x| x + 1);
+    /// assert_eq!(*val, 8);
+    /// ~~~
+    pub fn map<U, F>(self, f: F) -> Lazy<U>
+    where
+        E: 'static,
+        V: 'static,
+        F: FnOnce(V) -> U + 'static,
+    {
+        Thunk::new(Box::new(move || f(self.unwrap())))
+    }
+
+    /// Build a new, unforced thunk by chaining `self` into a function
+    /// producing another thunk.
+    ///
+    /// Neither `self` nor the thunk returned by `f` is forced until the
+    /// returned thunk is forced.
+    ///
+    /// ~~~
+    /// # use lazy_st::lazy;
+    /// let val = lazy!(7).and_then(|x| lazy!(x + 1));
+    /// assert_eq!(*val, 8);
+    /// ~~~
+    pub fn and_then<U, F>(self, f: F) -> Lazy<U>
+    where
+        E: 'static,
+        V: 'static,
+        F: FnOnce(V) -> Lazy<U> + 'static,
+    {
+        Thunk::new(Box::new(move || f(self.unwrap()).unwrap()))
+    }
+}
+
+impl<E, V> Thunk<E, V> {
+    /// Check whether the thunk has already been evaluated, without forcing it.
+    ///
+    /// ~~~
+    /// # use lazy_st::lazy;
+    /// let val = lazy!(7);
+    /// assert!(!val.is_evaluated());
+    /// assert_eq!(*val, 7);
+    /// assert!(val.is_evaluated());
+    /// ~~~
+    pub fn is_evaluated(&self) -> bool {
+        matches!(unsafe { &*self.0.get() }, Value(_))
+    }
+
+    /// Check whether the thunk was poisoned by a panic during a previous
+    /// evaluation.
+    pub fn is_poisoned(&self) -> bool {
+        matches!(unsafe { &*self.0.get() }, Poisoned)
+    }
+
+    /// Get a reference to the value of the thunk if it has already been
+    /// evaluated, without forcing it.
+    ///
+    /// ~~~
+    /// # use lazy_st::lazy;
+    /// let val = lazy!(7);
+    /// assert_eq!(val.peek(), None);
+    /// assert_eq!(*val, 7);
+    /// assert_eq!(val.peek(), Some(&7));
+    /// ~~~
+    pub fn peek(&self) -> Option<&V> {
+        match unsafe { &*self.0.get() } {
+            Value(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Get a mutable reference to the value of the thunk if it has already
+    /// been evaluated, without forcing it.
+    pub fn peek_mut(&mut self) -> Option<&mut V> {
+        match unsafe { &mut *self.0.get() } {
+            Value(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 /// Generalisation of lazy evaluation to other types than closures.
@@ -188,9 +290,21 @@ impl<A: FnOnce() -> B, B> Evaluate<B> for A {
 enum Inner<E, V> {
     Unevaluated(E),
     Evaluating,
+    Poisoned,
     Value(V),
 }
 
+/// Marks a thunk as `Poisoned` unless disarmed, so that a panic unwinding
+/// out of `evaluate()` leaves the thunk in a state that is distinguishable
+/// from a genuine re-entrant `force`.
+struct PoisonGuard<'a, E, V>(&'a UnsafeCell<Inner<E, V>>);
+
+impl<'a, E, V> Drop for PoisonGuard<'a, E, V> {
+    fn drop(&mut self) {
+        unsafe { *self.0.get() = Poisoned };
+    }
+}
+
 impl<E, V> Deref for Thunk<E, V>
 where
     E: Evaluate<V>,
@@ -218,3 +332,48 @@ where
         }
     }
 }
+
+/// Force the thunk and borrow its value.
+///
+/// As with `Box<T>`'s impl, this only covers the identity borrow `Borrow<V>`;
+/// it does not give you `Borrow<U>` for some `U` that `V` itself derefs to
+/// (e.g. no `Borrow<str>` for a `Thunk<_, String>`).
+impl<E, V> Borrow<V> for Thunk<E, V>
+where
+    E: Evaluate<V>,
+{
+    fn borrow(&self) -> &V {
+        Deref::deref(self)
+    }
+}
+
+impl<E, V> BorrowMut<V> for Thunk<E, V>
+where
+    E: Evaluate<V>,
+{
+    fn borrow_mut(&mut self) -> &mut V {
+        DerefMut::deref_mut(self)
+    }
+}
+
+/// Force the thunk and get a reference to its value.
+///
+/// Like [`Borrow`] above, this only supports the identity conversion
+/// `AsRef<V>`, matching `Box<T>`'s impl.
+impl<E, V> AsRef<V> for Thunk<E, V>
+where
+    E: Evaluate<V>,
+{
+    fn as_ref(&self) -> &V {
+        Deref::deref(self)
+    }
+}
+
+impl<E, V> AsMut<V> for Thunk<E, V>
+where
+    E: Evaluate<V>,
+{
+    fn as_mut(&mut self) -> &mut V {
+        DerefMut::deref_mut(self)
+    }
+}
@@ -0,0 +1,213 @@
+//! Lazy, memoized, singly-linked lists built on top of [`Thunk`](crate::Thunk).
+//!
+//! A [`LazyList`] is the spine of a Haskell-style list: each cell is a
+//! [`Thunk`](crate::Thunk) wrapped in an [`Rc`] so that clones share the very
+//! same memoized cell. Forcing a cell computes its head and yields the tail
+//! as another (possibly still unforced) `LazyList`. Because cells are shared,
+//! walking the same list more than once never re-runs a cell's closure.
+//!
+//! This makes it possible to build infinite lists with [`LazyList::iterate`]
+//! or [`LazyList::unfold`] and only ever pay for the elements you actually
+//! look at, e.g. via [`LazyList::take`]:
+//!
+//! ~~~
+//! use lazy_st::LazyList;
+//!
+//! let naturals = LazyList::iterate(0u32, |n| n + 1);
+//! let first_five: Vec<u32> = naturals.take(5).into_iter().cloned().collect();
+//! assert_eq!(first_five, [0, 1, 2, 3, 4]);
+//! ~~~
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use crate::{Lazy, Thunk};
+
+/// The contents of a single cell of a [`LazyList`]: either the empty list,
+/// or a head element together with the (lazy) rest of the list.
+///
+/// Not exported: it never appears in `LazyList`'s public API, which only
+/// ever hands out `T`s and `LazyList<T>`s.
+enum Cell<T: 'static> {
+    Nil,
+    Cons(T, LazyList<T>),
+}
+
+/// An immutable, lazily evaluated, memoized singly-linked list.
+///
+/// Cloning a `LazyList` is cheap: it just bumps the reference count of the
+/// shared cell, so all clones see the same memoized elements.
+pub struct LazyList<T: 'static>(Rc<Lazy<Cell<T>>>);
+
+impl<T> Clone for LazyList<T> {
+    fn clone(&self) -> Self {
+        LazyList(self.0.clone())
+    }
+}
+
+impl<T> Drop for LazyList<T> {
+    /// Unwinds the spine iteratively instead of relying on the
+    /// compiler-derived recursive glue (`LazyList` drops its tail, which
+    /// drops its tail, ...), which would blow the stack for long lists.
+    ///
+    /// Only cells we exclusively own *and* that are already forced can be
+    /// unwound this way; an unevaluated or still-shared cell stops the
+    /// loop and is left to drop normally (as `std`'s linked-list types do).
+    fn drop(&mut self) {
+        let mut next = take_tail(&mut self.0);
+        while let Some(mut list) = next {
+            next = take_tail(&mut list.0);
+        }
+    }
+}
+
+/// If `rc` is uniquely owned and already forced, replaces its cell with
+/// [`Cell::Nil`] (so the `Rc`'s own drop glue has nothing left to recurse
+/// into) and returns the tail that used to be there.
+fn take_tail<T>(rc: &mut Rc<Lazy<Cell<T>>>) -> Option<LazyList<T>> {
+    match Rc::get_mut(rc).and_then(Thunk::peek_mut) {
+        Some(cell @ Cell::Cons(..)) => match core::mem::replace(cell, Cell::Nil) {
+            Cell::Cons(_, tail) => Some(tail),
+            Cell::Nil => unreachable!(),
+        },
+        _ => None,
+    }
+}
+
+impl<T: 'static> LazyList<T> {
+    /// The empty list.
+    pub fn nil() -> Self {
+        LazyList(Rc::new(Thunk::evaluated(Cell::Nil)))
+    }
+
+    /// Construct a list from a head element and a closure producing the tail.
+    ///
+    /// The tail closure is not run until the returned list is forced for the
+    /// first time (e.g. by calling [`head`](Self::head) or [`tail`](Self::tail)),
+    /// and the result is memoized for every later access.
+    pub fn cons<F>(head: T, tail: F) -> Self
+    where
+        F: FnOnce() -> LazyList<T> + 'static,
+    {
+        LazyList(Rc::new(Thunk::new(Box::new(move || {
+            Cell::Cons(head, tail())
+        }))))
+    }
+
+    /// Build a (possibly infinite) list by repeatedly applying `f` to a seed,
+    /// where `f` yields the next element together with the next seed.
+    ///
+    /// Nothing beyond the seed is computed until the list is forced.
+    pub fn unfold<S, F>(seed: S, f: F) -> Self
+    where
+        S: 'static,
+        F: Fn(S) -> (T, S) + 'static,
+    {
+        LazyList(Rc::new(Thunk::new(Box::new(move || {
+            let (head, next) = f(seed);
+            Cell::Cons(head, Self::unfold(next, f))
+        }))))
+    }
+
+    /// Build the (possibly infinite) list `seed, f(seed), f(f(seed)), ...`.
+    pub fn iterate<F>(seed: T, f: F) -> Self
+    where
+        T: Clone,
+        F: Fn(T) -> T + 'static,
+    {
+        Self::unfold(seed, move |s: T| {
+            let next = f(s.clone());
+            (s, next)
+        })
+    }
+
+    /// The first element of the list, or `None` if the list is empty.
+    ///
+    /// Forces (and memoizes) the list's first cell.
+    pub fn head(&self) -> Option<&T> {
+        match &**self.0 {
+            Cell::Nil => None,
+            Cell::Cons(head, _) => Some(head),
+        }
+    }
+
+    /// The rest of the list, or `None` if the list is empty.
+    ///
+    /// Forces (and memoizes) the list's first cell.
+    pub fn tail(&self) -> Option<&LazyList<T>> {
+        match &**self.0 {
+            Cell::Nil => None,
+            Cell::Cons(_, tail) => Some(tail),
+        }
+    }
+
+    /// A new list containing at most the first `n` elements of `self`.
+    ///
+    /// Unless `n` is 0, the first cell of `self` is forced right away. The
+    /// returned list is made of new cells holding clones of `self`'s heads;
+    /// cell `k + 1` of `self` is forced (and memoized in `self`) only when cell
+    /// `k` of the returned list is forced, and elements beyond `n` are never
+    /// forced.
+    pub fn take(&self, n: usize) -> LazyList<T>
+    where
+        T: Clone,
+    {
+        if n == 0 {
+            return Self::nil();
+        }
+        match &**self.0 {
+            Cell::Nil => Self::nil(),
+            Cell::Cons(head, tail) => {
+                let head = head.clone();
+                let tail = tail.clone();
+                Self::cons(head, move || tail.take(n - 1))
+            }
+        }
+    }
+}
+
+/// An iterator over the elements of a [`LazyList`], forcing each cell as it goes.
+pub struct Iter<'a, T: 'static>(Option<&'a LazyList<T>>);
+
+impl<'a, T: 'static> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let list = self.0?;
+        match &**list.0 {
+            Cell::Nil => {
+                self.0 = None;
+                None
+            }
+            Cell::Cons(head, tail) => {
+                self.0 = Some(tail);
+                Some(head)
+            }
+        }
+    }
+}
+
+impl<'a, T: 'static> IntoIterator for &'a LazyList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        Iter(Some(self))
+    }
+}
+
+/// Build a list eagerly from an iterator.
+///
+/// Unlike [`cons`](LazyList::cons), every cell is already evaluated, so no
+/// laziness is involved; this is meant for turning ordinary, finite data
+/// into a `LazyList` to feed into combinators that expect one.
+impl<T: 'static> FromIterator<T> for LazyList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let elems: Vec<T> = iter.into_iter().collect();
+        elems.into_iter().rev().fold(Self::nil(), |tail, head| {
+            LazyList(Rc::new(Thunk::evaluated(Cell::Cons(head, tail))))
+        })
+    }
+}
@@ -1,4 +1,6 @@
-use lazy_st::{lazy, Lazy};
+use lazy_st::{lazy, Lazy, LazyList};
+use std::borrow::Borrow;
+use std::iter::FromIterator;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
@@ -68,3 +70,135 @@ fn drop_internal_data_just_once() {
         _ => panic!("Unexpected success in spawned task."),
     }
 }
+
+#[test]
+fn map_does_not_force_until_forced() {
+    let counter = Arc::new(Mutex::new(0));
+    let counter_clone = counter.clone();
+    let val = lazy!({
+        *counter_clone.lock().unwrap() += 1;
+        7
+    })
+    .map(|x| x + 1);
+    assert_eq!(*counter.lock().unwrap(), 0);
+    assert_eq!(val.unwrap(), 8);
+}
+
+#[test]
+fn and_then_chains_thunks() {
+    let val: Lazy<u32> = lazy!(7).and_then(|x| lazy!(x + 1));
+    assert_eq!(val.unwrap(), 8);
+}
+
+#[test]
+fn borrow_and_as_ref_force_and_return_value() {
+    // Only the identity conversions are supported (as with `Box<T>`), so
+    // looking `key` up requires a `HashMap<String, _>`, not `HashMap<str, _>`.
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    map.insert(String::from("key"), 7);
+
+    let key: Lazy<String> = lazy!(String::from("key"));
+    assert_eq!(map.get(Borrow::<String>::borrow(&key)), Some(&7));
+    assert_eq!(AsRef::<String>::as_ref(&key).as_str(), "key");
+}
+
+#[test]
+fn is_poisoned_reports_state() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let t: Lazy<()> = lazy!(panic!("Muahahahah"));
+    assert!(!t.is_poisoned());
+    let _ = catch_unwind(AssertUnwindSafe(|| t.force()));
+    assert!(t.is_poisoned());
+
+    let result = catch_unwind(AssertUnwindSafe(|| t.force()));
+    match result {
+        Err(e) => {
+            let msg = e.downcast_ref::<&str>().unwrap();
+            assert!(msg.contains("poisoned"));
+        }
+        _ => panic!("Expected force() to panic on a poisoned thunk."),
+    }
+}
+
+#[test]
+fn peek_does_not_force() {
+    let counter = Arc::new(Mutex::new(0));
+    let counter_clone = counter.clone();
+    let val = lazy!({
+        *counter_clone.lock().unwrap() += 1;
+        7
+    });
+    assert!(!val.is_evaluated());
+    assert_eq!(val.peek(), None);
+    assert_eq!(*counter.lock().unwrap(), 0);
+    assert_eq!(*val, 7);
+    assert!(val.is_evaluated());
+    assert_eq!(val.peek(), Some(&7));
+    assert_eq!(*counter.lock().unwrap(), 1);
+}
+
+#[test]
+fn lazy_list_unfold_does_not_run_f_until_forced() {
+    let counter = Arc::new(Mutex::new(0));
+    let counter_clone = counter.clone();
+    let list = LazyList::unfold(0u32, move |n| {
+        *counter_clone.lock().unwrap() += 1;
+        (n, n + 1)
+    });
+    assert_eq!(*counter.lock().unwrap(), 0);
+    assert_eq!(list.head(), Some(&0));
+    assert_eq!(*counter.lock().unwrap(), 1);
+}
+
+#[test]
+fn lazy_list_iterate_does_not_run_f_until_forced() {
+    let counter = Arc::new(Mutex::new(0));
+    let counter_clone = counter.clone();
+    let list = LazyList::iterate(0u32, move |n| {
+        *counter_clone.lock().unwrap() += 1;
+        n + 1
+    });
+    assert_eq!(*counter.lock().unwrap(), 0);
+    assert_eq!(list.head(), Some(&0));
+    assert_eq!(*counter.lock().unwrap(), 1);
+}
+
+#[test]
+fn lazy_list_take_from_infinite() {
+    let naturals = LazyList::iterate(0u32, |n| n + 1);
+    let taken: Vec<u32> = naturals.take(5).into_iter().cloned().collect();
+    assert_eq!(taken, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn lazy_list_tail_evaluated_just_once() {
+    let counter = Arc::new(Mutex::new(0));
+    let counter_clone = counter.clone();
+    let list = LazyList::cons(1, move || {
+        *counter_clone.lock().unwrap() += 1;
+        LazyList::nil()
+    });
+    assert_eq!(list.tail().unwrap().head(), None);
+    assert_eq!(list.tail().unwrap().head(), None);
+    assert_eq!(*counter.lock().unwrap(), 1);
+}
+
+#[test]
+fn lazy_list_from_iter() {
+    let list = LazyList::from_iter(vec![1, 2, 3]);
+    let collected: Vec<i32> = (&list).into_iter().cloned().collect();
+    assert_eq!(collected, [1, 2, 3]);
+}
+
+#[test]
+fn lazy_list_drop_does_not_overflow_the_stack() {
+    // Regression test: dropping a long, fully-forced list used to recurse
+    // one stack frame per cell via the compiler-derived `Drop` glue.
+    let long_list: LazyList<i64> = LazyList::iterate(0, |n| n + 1).take(1_000_000);
+    let sum: i64 = (&long_list).into_iter().sum();
+    assert_eq!(sum, 499_999_500_000);
+    drop(long_list);
+}